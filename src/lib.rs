@@ -1,25 +1,32 @@
 extern crate log;
 
-use std::collections::DList;
-use std::mem::swap;
+use std::mem::{swap, size_of};
+use std::num::Int;
 use std::fmt::Show; // FIXME debug only
+use std::slice;
+use std::vec;
+use std::cmp::Ordering;
+use std::ops::{Range, Index, IndexMut};
 
-// Dependencies for Slice
-// use std::ops::Slice;
-// use std::rc::Rc;
+macro_rules! INITIAL_SZ { () => { 512us } }
 
-macro_rules! PAGE_SIZE { () => { 512us } }
+// At or below this size, `introsort` falls back to insertion sort rather than recursing further.
+const INSERTION_SORT_THRESHOLD: usize = 20;
 
 /// An Unrolled Linked List.
+/// Pages grow geometrically: page `k` holds `initial_sz << k` elements, so the list starts
+/// with a small allocation and doubles page capacity as it grows, trading a handful of larger
+/// allocations for true O(1) random access (pages live in a `Vec`, addressed directly).
 /// Removing an item from the middle of the list will move the last item to that position, preventing fragmentation.
 pub struct Unrolled<T: Copy + Show> {
-	psize: usize,
-	dlist: DList<Page<T>>,
-	len:   usize, // FIXME could be atomic for thread safety?
+	initial_sz: usize,
+	shift:      usize, // log2(initial_sz); initial_sz must be a power of two
+	pages:      Vec<Page<T>>,
+	len:        usize, // FIXME could be atomic for thread safety?
 }
 
 struct Page<T> {
-	items: Vec<T>, // Must have capaciy of PAGE_SIZE and never shrink/grow/move
+	items: Vec<T>, // Must have capacity equal to this page's share and never shrink/grow/move
 }
 
 impl<T> Page<T> {
@@ -30,25 +37,101 @@ impl<T> Page<T> {
 	}
 }
 
+/// Immutable iterator over an `Unrolled<T>`, yielding items in logical order.
+/// Walks the current page's slice iterator and advances to the next page once it's exhausted,
+/// mirroring the standard `slice::Iter` design.
+pub struct Iter<'a, T: 'a> {
+	cur:   slice::Iter<'a, T>,
+	pages: slice::Iter<'a, Page<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<&'a T> {
+		loop {
+			if let Some(item) = self.cur.next() {
+				return Some(item);
+			}
+
+			match self.pages.next() {
+				Some(page) => self.cur = page.items.iter(),
+				None => return None,
+			}
+		}
+	}
+}
+
+/// Mutable iterator over an `Unrolled<T>`, yielding items in logical order.
+pub struct IterMut<'a, T: 'a> {
+	cur:   slice::IterMut<'a, T>,
+	pages: slice::IterMut<'a, Page<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+	type Item = &'a mut T;
+
+	fn next(&mut self) -> Option<&'a mut T> {
+		loop {
+			if let Some(item) = self.cur.next() {
+				return Some(item);
+			}
+
+			match self.pages.next() {
+				Some(page) => self.cur = page.items.iter_mut(),
+				None => return None,
+			}
+		}
+	}
+}
+
+/// Owning iterator over an `Unrolled<T>`, produced by `IntoIterator`.
+pub struct IntoIter<T> {
+	cur:   vec::IntoIter<T>,
+	pages: vec::IntoIter<Page<T>>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+	type Item = T;
+
+	fn next(&mut self) -> Option<T> {
+		loop {
+			if let Some(item) = self.cur.next() {
+				return Some(item);
+			}
+
+			match self.pages.next() {
+				Some(page) => self.cur = page.items.into_iter(),
+				None => return None,
+			}
+		}
+	}
+}
+
 impl<'a, T: Copy + Show> Unrolled<T> {
-	pub fn new(page_size: usize) -> Unrolled<T> {
+	/// `initial_sz` is the capacity of the first page and must be a power of two;
+	/// page `k` holds `initial_sz << k` elements.
+	pub fn new(initial_sz: usize) -> Unrolled<T> {
 		Unrolled {
-			psize: page_size,
-			dlist: DList::new(), // No pages are pre-allocated
-			len:   0,
+			initial_sz: initial_sz,
+			shift:      initial_sz.trailing_zeros(),
+			pages:      Vec::new(), // No pages are pre-allocated
+			len:        0,
 		}
 	}
 
 	/// Insert an item at the end of the list.
 	pub fn push(&mut self, item: T) {
-		// Make sure there's enough space for the item
-		if !self.enough_pages_for(self.len + 1) {
-			self.dlist.push_back(Page::new(self.psize));
+		let (page, _) = self.locate(self.len);
+
+		// Make sure there's a page to hold the item
+		if page == self.pages.len() {
+			self.pages.push(Page::new(self.page_capacity(page)));
 		};
 
-		self.len += 1;
+		self.pages[page].items.push(item);
 
-		self.dlist.back_mut().unwrap().items.push(item);
+		self.len += 1;
 	}
 
 	/// Remove an item from the end of the list and return it.
@@ -58,14 +141,10 @@ impl<'a, T: Copy + Show> Unrolled<T> {
 			return None;
 		}
 
-		let page = self.page_of(self.len);
-		match self.dlist.iter_mut().nth(page).unwrap().items.pop() {
-			Some(item) => {
-				self.len -= 1;
-				Some(item)
-			},
-			None => None,
-		}
+		self.len -= 1;
+
+		let (page, _) = self.locate(self.len);
+		self.pages[page].items.pop()
 	}
 
 	pub fn len(&self) -> usize {
@@ -73,18 +152,18 @@ impl<'a, T: Copy + Show> Unrolled<T> {
 	}
 
 	pub fn get_mut(&mut self, pos: usize) -> Option<&mut T> {
-		let page = self.page_of(pos);
-		self.dlist.iter_mut().nth(page).unwrap().items.as_mut_slice().get_mut(pos % self.psize)
+		let (page, offset) = self.locate(pos);
+		self.pages.get_mut(page).and_then(|p| p.items.get_mut(offset))
 	}
 
 	pub fn get(&self, pos: usize) -> Option<&T> {
-		self.dlist.iter().nth(self.page_of(pos)).unwrap().items.as_slice().get(pos % self.psize)
+		let (page, offset) = self.locate(pos);
+		self.pages.get(page).and_then(|p| p.items.get(offset))
 	}
 
 	/// Removes and returns the item at a given position.
 	/// Returns None if no item exists at that position.
 	pub fn remove(&mut self, pos: usize) -> Option<T> {
-		// What I want to write...
 		let max_idx = self.len - 1;
 
 		if pos > max_idx || self.len == 0 {
@@ -93,130 +172,428 @@ impl<'a, T: Copy + Show> Unrolled<T> {
 
 		// Swap with last, unless it's last
 		if pos != max_idx {
-			let item_offset = pos % self.psize;
-			let last_offset = max_idx % self.psize;
-			let page_pos = self.page_of(pos);
-			let page_max = self.page_of(max_idx);
-
-			let mut pages = self.mut_slice_pages();
-
-			// Check if it's on the last page
-			if page_pos != page_max {
-				let (item_page, last_page) = pages.as_mut_slice().split_at_mut(page_max);
+			self.swap_items(pos, max_idx);
+		}
 
-				swap(
-					item_page.get_mut(item_offset).unwrap(),
-					last_page.get_mut(last_offset).unwrap()
-				);
-			} else {
-				let (item, last) = pages[page_pos].split_at_mut(last_offset);
-				let item_len = item.len();
+		self.pop()
+	}
 
-				swap(
-					item.get_mut(item_offset).unwrap(),
-					last.get_mut(last_offset - item_len).unwrap()
-				);
-			}
+	/// Inserts an item at a given position, preserving order by shifting everything
+	/// from `pos` onward forward by one (across page boundaries as needed).
+	/// Panics if `pos > len()`.
+	pub fn insert(&mut self, pos: usize, item: T) {
+		assert!(pos <= self.len, "insert position out of bounds");
+
+		// Grow by one, then shift the tail right into the new slot, working backwards
+		// so each item is only read once it's no longer needed at its old position.
+		self.push(item);
+
+		let mut i = self.len - 1;
+		while i > pos {
+			let prev = *self.get(i - 1).unwrap();
+			*self.get_mut(i).unwrap() = prev;
+			i -= 1;
 		}
 
-		self.pop()
+		*self.get_mut(pos).unwrap() = item;
 	}
 
-	fn mut_slice_pages(&'a mut self) -> Vec<&'a mut[T]> {
-		let mut slices: Vec<&mut[T]> = Vec::new();
+	/// Removes the items in `range`, returning them as an iterator, and shifts the
+	/// remaining tail left to close the gap. Panics if the range is out of bounds.
+	pub fn drain(&mut self, range: Range<usize>) -> vec::IntoIter<T> {
+		let Range { start, end } = range;
+		assert!(start <= end && end <= self.len, "drain range out of bounds");
 
-		for p in self.dlist.iter_mut() {
-			slices.push((*p.items).as_mut_slice());
+		let mut drained: Vec<T> = Vec::with_capacity(end - start);
+		for i in start..end {
+			drained.push(*self.get(i).unwrap());
 		}
 
-		slices
+		let tail_len = self.len - end;
+		for offset in 0..tail_len {
+			let value = *self.get(end + offset).unwrap();
+			*self.get_mut(start + offset).unwrap() = value;
+		}
+
+		for _ in 0..(end - start) {
+			self.pop();
+		}
+
+		drained.into_iter()
 	}
 
-	// Check if enough pages exist to hold a given index
-	#[inline]
-	fn enough_pages_for(&self, pos: usize) -> bool {
-		match self.dlist.len() {
-			0 => false,
-			_ => self.page_of(pos) <= self.dlist.len() - 1,
+	// Swaps the items at two logical positions, whether they live on the same page or
+	// on different pages. Shared by `remove` and the sort routines below.
+	fn swap_items(&mut self, a: usize, b: usize) {
+		if a == b {
+			return;
+		}
+
+		let (page_a, off_a) = self.locate(a);
+		let (page_b, off_b) = self.locate(b);
+
+		if page_a != page_b {
+			let (lo_page, lo_off, hi_page, hi_off) = if page_a < page_b {
+				(page_a, off_a, page_b, off_b)
+			} else {
+				(page_b, off_b, page_a, off_a)
+			};
+
+			let (front, back) = self.pages.split_at_mut(hi_page);
+
+			swap(
+				&mut front[lo_page].items[lo_off],
+				&mut back[0].items[hi_off]
+			);
+		} else {
+			self.pages[page_a].items.swap(off_a, off_b);
 		}
 	}
 
 	// Returns the zero-indexed page that a zero-indexed item is on
 	pub fn page_of(&self, pos: usize) -> usize {
-		pos / self.psize
+		self.locate(pos).0
+	}
+
+	// Maps a logical index to (page, offset) in O(1) without scanning the page list.
+	// `pos + initial_sz`, shifted down by log2(initial_sz), has exactly `page + 1`
+	// significant bits, so `leading_zeros` recovers `page` directly; the page's base
+	// index then falls out of the same geometric series used to size the page.
+	#[inline]
+	fn locate(&self, pos: usize) -> (usize, usize) {
+		let shifted = (pos + self.initial_sz) >> self.shift;
+		let width = size_of::<usize>() * 8;
+		let page = width - shifted.leading_zeros() as usize - 1;
+		let base = (self.initial_sz << page) - self.initial_sz;
+
+		(page, pos - base)
 	}
+
+	#[inline]
+	fn page_capacity(&self, page: usize) -> usize {
+		self.initial_sz << page
+	}
+
+	/// Returns an iterator over references to the items, in logical order.
+	pub fn iter(&'a self) -> Iter<'a, T> {
+		let mut pages = self.pages.iter();
+		let cur = match pages.next() {
+			Some(page) => page.items.iter(),
+			None => [].iter(),
+		};
+
+		Iter { cur: cur, pages: pages }
+	}
+
+	/// Returns an iterator over mutable references to the items, in logical order.
+	pub fn iter_mut(&'a mut self) -> IterMut<'a, T> {
+		let mut pages = self.pages.iter_mut();
+		let cur = match pages.next() {
+			Some(page) => page.items.iter_mut(),
+			None => [].iter_mut(),
+		};
+
+		IterMut { cur: cur, pages: pages }
+	}
+
+	/// Returns an iterator over each page's populated slice, for bulk/vectorized processing.
+	/// Every page's `Vec` already holds exactly its populated items -- including a partially
+	/// filled last page -- so no separate clipping is needed.
+	pub fn chunks(&'a self) -> Chunks<'a, T> {
+		Chunks { pages: self.pages.iter() }
+	}
+
+	/// Mutable counterpart to `chunks`.
+	pub fn chunks_mut(&'a mut self) -> ChunksMut<'a, T> {
+		ChunksMut { pages: self.pages.iter_mut() }
+	}
+
+	/// Collects every page's populated slice into a `Vec`.
+	pub fn as_slices(&'a self) -> Vec<&'a [T]> {
+		self.chunks().collect()
+	}
+}
+
+/// Iterator over each page's populated slice, produced by `Unrolled::chunks`.
+pub struct Chunks<'a, T: 'a> {
+	pages: slice::Iter<'a, Page<T>>,
 }
 
-/*
-FIXME huon might implement reference counted slicing, which would make Slice much easier to implement here
-impl<'c, T> Slice<usize, [&'c [T]]> for Unrolled<'c, T> {
-    fn as_slice_<'a>(&'a self) -> &'a [&'c [T]] {
-		let v: Vec<&'c [T]> = self.dlist
-			.iter()
-			.by_ref()
-			.map(|&page| page.items.as_slice())
-			.collect();
+impl<'a, T> Iterator for Chunks<'a, T> {
+	type Item = &'a [T];
 
-		Rc::new(v).as_slice()
+	fn next(&mut self) -> Option<&'a [T]> {
+		self.pages.next().map(|page| page.items.as_slice())
 	}
+}
+
+/// Iterator over each page's populated mutable slice, produced by `Unrolled::chunks_mut`.
+pub struct ChunksMut<'a, T: 'a> {
+	pages: slice::IterMut<'a, Page<T>>,
+}
 
-    fn slice_from_or_fail<'a>(&'a self, from: &usize) -> &'a [&'c [T]] {
-		let v: Vec<&'c [T]> = self.dlist
-			.iter()
-			.by_ref()
-			.skip(page_of(*from) - 1)
-			.map(|&page| page.items.as_slice())
-			.collect();
+impl<'a, T> Iterator for ChunksMut<'a, T> {
+	type Item = &'a mut [T];
 
-		let slice = (box v).as_slice();
+	fn next(&mut self) -> Option<&'a mut [T]> {
+		self.pages.next().map(|page| page.items.as_mut_slice())
+	}
+}
 
-		// Clip the front slice up to `*from`
-		slice[0] = slice[0][page_offset(*from)..];
+impl<T: Copy + Show> IntoIterator for Unrolled<T> {
+	type Item = T;
+	type IntoIter = IntoIter<T>;
+
+	fn into_iter(self) -> IntoIter<T> {
+		let mut pages = self.pages.into_iter();
+		let cur = match pages.next() {
+			Some(page) => page.items.into_iter(),
+			None => Vec::new().into_iter(),
+		};
 
-		slice
+		IntoIter { cur: cur, pages: pages }
 	}
+}
 
-    fn slice_to_or_fail<'a>(&'a self, to: &usize) -> &'a [&'c [T]] {
-		let v: Vec<&'c [T]> = self.dlist
-			.iter()
-			.by_ref()
-			.take(page_of(*to))
-			.map(|&page| page.items.as_slice())
-			.collect();
+impl<'a, T: Copy + Show> IntoIterator for &'a Unrolled<T> {
+	type Item = &'a T;
+	type IntoIter = Iter<'a, T>;
 
-		let slice = (box v).as_slice();
+	fn into_iter(self) -> Iter<'a, T> {
+		self.iter()
+	}
+}
 
-		let last = slice.len() - 1;
+impl<'a, T: Copy + Show> IntoIterator for &'a mut Unrolled<T> {
+	type Item = &'a mut T;
+	type IntoIter = IterMut<'a, T>;
 
-		// Clip the back slice after `*to`
-		slice[last] = slice[last][..page_offset(*to) - 1];
+	fn into_iter(self) -> IterMut<'a, T> {
+		self.iter_mut()
+	}
+}
+
+impl<T: Copy + Show + Ord> Unrolled<T> {
+	/// Sorts the list in place. Not guaranteed to be stable, nor does it allocate auxiliary memory.
+	pub fn sort_unstable(&mut self) {
+		self.sort_unstable_by(|a, b| a.cmp(b));
+	}
+}
+
+impl<T: Copy + Show> Unrolled<T> {
+	/// Sorts the list in place with a comparator, using an introsort over the logical index
+	/// space: quicksort with a median-of-three pivot, falling back to insertion sort at or below
+	/// `INSERTION_SORT_THRESHOLD` elements, and to heapsort once recursion depth exceeds
+	/// `2 * floor(log2(len))` -- bounding the worst case to O(n log n) regardless of input.
+	/// Swaps go through `swap_items`, so operands may live on different pages transparently.
+	pub fn sort_unstable_by<F>(&mut self, mut compare: F) where F: FnMut(&T, &T) -> Ordering {
+		let len = self.len;
+
+		if len < 2 {
+			return;
+		}
 
-		slice
+		let depth_limit = 2 * log2_floor(len);
+		self.introsort(0, len - 1, depth_limit, &mut compare);
 	}
 
-    fn slice_or_fail<'a>(&'a self, from: &usize, to: &usize) -> &'a [&'c [T]] {
-		let diff = *to - *from;
+	/// Sorts the list in place by a key extracted from each element.
+	pub fn sort_unstable_by_key<K, F>(&mut self, mut f: F) where K: Ord, F: FnMut(&T) -> K {
+		self.sort_unstable_by(|a, b| f(a).cmp(&f(b)));
+	}
+
+	fn introsort<F>(&mut self, lo: usize, hi: usize, depth: usize, compare: &mut F) where F: FnMut(&T, &T) -> Ordering {
+		if hi <= lo {
+			return;
+		}
+
+		if hi - lo + 1 <= INSERTION_SORT_THRESHOLD {
+			self.insertion_sort(lo, hi, compare);
+		} else if depth == 0 {
+			self.heapsort(lo, hi, compare);
+		} else {
+			let mid = self.partition(lo, hi, compare);
 
-		let v: Vec<&'c [T]> = self.dlist
-			.iter()
-			.by_ref()
-			.skip(page_of(*from) - 1)
-			.take(diff)
-			.map(|&page| page.items.as_slice())
-			.collect();
+			if mid > lo {
+				self.introsort(lo, mid - 1, depth - 1, compare);
+			}
+
+			self.introsort(mid + 1, hi, depth - 1, compare);
+		}
+	}
 
-		let slice = (box v).as_slice();
+	fn insertion_sort<F>(&mut self, lo: usize, hi: usize, compare: &mut F) where F: FnMut(&T, &T) -> Ordering {
+		let mut i = lo + 1;
 
-		let last = slice.len() - 1;
+		while i <= hi {
+			let mut j = i;
 
-		// Clip the front and back slices *to fit `*from` and `*to`
-		slice[0] = slice[0][page_offset(*from)..];
-		slice[last] = slice[last][..page_offset(*from) - 1];
+			while j > lo && compare(self.get(j).unwrap(), self.get(j - 1).unwrap()) == Ordering::Less {
+				self.swap_items(j, j - 1);
+				j -= 1;
+			}
 
-		slice
+			i += 1;
+		}
+	}
+
+	// Median-of-three quicksort partition: order `lo`/`mid`/`hi`, stash the median as the
+	// pivot just before `hi`, then partition the rest around it.
+	fn partition<F>(&mut self, lo: usize, hi: usize, compare: &mut F) -> usize where F: FnMut(&T, &T) -> Ordering {
+		let mid = lo + (hi - lo) / 2;
+
+		if compare(self.get(mid).unwrap(), self.get(lo).unwrap()) == Ordering::Less {
+			self.swap_items(lo, mid);
+		}
+		if compare(self.get(hi).unwrap(), self.get(lo).unwrap()) == Ordering::Less {
+			self.swap_items(lo, hi);
+		}
+		if compare(self.get(hi).unwrap(), self.get(mid).unwrap()) == Ordering::Less {
+			self.swap_items(mid, hi);
+		}
+
+		let pivot_idx = hi - 1;
+		self.swap_items(mid, pivot_idx);
+
+		let mut i = lo;
+		let mut j = pivot_idx;
+
+		loop {
+			loop {
+				i += 1;
+				if compare(self.get(i).unwrap(), self.get(pivot_idx).unwrap()) != Ordering::Less {
+					break;
+				}
+			}
+
+			loop {
+				j -= 1;
+				if compare(self.get(pivot_idx).unwrap(), self.get(j).unwrap()) != Ordering::Less {
+					break;
+				}
+			}
+
+			if i >= j {
+				break;
+			}
+
+			self.swap_items(i, j);
+		}
+
+		self.swap_items(i, pivot_idx);
+		i
+	}
+
+	fn heapsort<F>(&mut self, lo: usize, hi: usize, compare: &mut F) where F: FnMut(&T, &T) -> Ordering {
+		let len = hi - lo + 1;
+
+		for start in (0..len / 2).rev() {
+			self.sift_down(lo, start, len - 1, compare);
+		}
+
+		for end in (1..len).rev() {
+			self.swap_items(lo, lo + end);
+			self.sift_down(lo, 0, end - 1, compare);
+		}
+	}
+
+	fn sift_down<F>(&mut self, base: usize, start: usize, end: usize, compare: &mut F) where F: FnMut(&T, &T) -> Ordering {
+		let mut root = start;
+
+		loop {
+			let mut child = 2 * root + 1;
+
+			if child > end {
+				break;
+			}
+
+			if child < end && compare(self.get(base + child).unwrap(), self.get(base + child + 1).unwrap()) == Ordering::Less {
+				child += 1;
+			}
+
+			if compare(self.get(base + root).unwrap(), self.get(base + child).unwrap()) == Ordering::Less {
+				self.swap_items(base + root, base + child);
+				root = child;
+			} else {
+				break;
+			}
+		}
+	}
+}
+
+// floor(log2(n)) for n >= 1, used to bound introsort's recursion depth before falling back to heapsort.
+#[inline]
+fn log2_floor(n: usize) -> usize {
+	size_of::<usize>() * 8 - 1 - n.leading_zeros() as usize
+}
+
+impl<T: Copy + Show> Index<usize> for Unrolled<T> {
+	type Output = T;
+
+	fn index(&self, index: usize) -> &T {
+		self.get(index).expect("index out of bounds")
+	}
+}
+
+impl<T: Copy + Show> IndexMut<usize> for Unrolled<T> {
+	fn index_mut(&mut self, index: usize) -> &mut T {
+		self.get_mut(index).expect("index out of bounds")
+	}
+}
+
+impl<'a, T: Copy + Show> Unrolled<T> {
+	/// Returns a borrowing iterator over the logical sub-range `bounds`, built directly from
+	/// the underlying page slices (clipping the first and last page's slice to the requested
+	/// bounds) rather than copying.
+	pub fn range(&'a self, bounds: Range<usize>) -> RangeIter<'a, T> {
+		let Range { start, end } = bounds;
+		assert!(start <= end && end <= self.len, "range bounds out of bounds");
+
+		let mut slices: Vec<&'a [T]> = Vec::new();
+
+		if start < end {
+			let (start_page, start_off) = self.locate(start);
+			let (end_page, end_off) = self.locate(end - 1); // last included index
+
+			if start_page == end_page {
+				slices.push(&self.pages[start_page].items[start_off..end_off + 1]);
+			} else {
+				slices.push(&self.pages[start_page].items[start_off..]);
+
+				for page in &self.pages[start_page + 1..end_page] {
+					slices.push(page.items.as_slice());
+				}
+
+				slices.push(&self.pages[end_page].items[..end_off + 1]);
+			}
+		}
+
+		RangeIter { slices: slices.into_iter(), cur: [].iter() }
+	}
+}
+
+/// Borrowing iterator over a logical sub-range, produced by `Unrolled::range`.
+pub struct RangeIter<'a, T: 'a> {
+	slices: vec::IntoIter<&'a [T]>,
+	cur:    slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for RangeIter<'a, T> {
+	type Item = &'a T;
+
+	fn next(&mut self) -> Option<&'a T> {
+		loop {
+			if let Some(item) = self.cur.next() {
+				return Some(item);
+			}
+
+			match self.slices.next() {
+				Some(s) => self.cur = s.iter(),
+				None => return None,
+			}
+		}
 	}
 }
-*/
 
 #[cfg(test)]
 mod tests {
@@ -225,22 +602,23 @@ mod tests {
 
 	#[test]
 	fn utilities() {
-		let psize = 10us;
+		let psize = 8us;
 		let list: Unrolled<usize> = Unrolled::new(psize);
 		assert!(list.page_of(0) == 0);
-		assert!(list.page_of(1) == 0);
-		assert!(list.page_of(psize) == 1);
 		assert!(list.page_of(psize - 1) == 0);
-		assert!(list.page_of(psize * 2) == 2);
-		assert!(list.page_of(psize * 2 + 1) == 2);
+		assert!(list.page_of(psize) == 1);
+		assert!(list.page_of(psize * 3 - 1) == 1); // last index of page 1 (cap 2*psize)
+		assert!(list.page_of(psize * 3) == 2);
+		assert!(list.page_of(psize * 7 - 1) == 2); // last index of page 2 (cap 4*psize)
+		assert!(list.page_of(psize * 7) == 3);
 	}
 
 	#[test]
 	fn smoke_push_pop() {
-		let psize = 10us;
+		let psize = 8us;
 		let mut list: Unrolled<i32> = Unrolled::new(psize);
 
-		assert!(list.dlist.len() == 0);
+		assert!(list.pages.len() == 0);
 
 		let psize: i32 = psize as i32;
 
@@ -271,9 +649,127 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn smoke_iter() {
+		let mut list: Unrolled<i32> = Unrolled::new(8us);
+
+		for n in 0i32..30i32 {
+			list.push(n);
+		}
+
+		let collected: Vec<i32> = list.iter().map(|&n| n).collect();
+		assert!(collected == (0i32..30i32).collect::<Vec<i32>>());
+
+		for item in list.iter_mut() {
+			*item += 1;
+		}
+
+		let collected: Vec<i32> = (&list).into_iter().map(|&n| n).collect();
+		assert!(collected == (1i32..31i32).collect::<Vec<i32>>());
+
+		let collected: Vec<i32> = list.into_iter().collect();
+		assert!(collected == (1i32..31i32).collect::<Vec<i32>>());
+	}
+
+	#[test]
+	fn smoke_chunks() {
+		let mut list: Unrolled<i32> = Unrolled::new(8us);
+
+		for n in 0i32..20i32 {
+			list.push(n);
+		}
+
+		let flattened: Vec<i32> = list.chunks().flat_map(|s| s.iter()).map(|&n| n).collect();
+		assert!(flattened == (0i32..20i32).collect::<Vec<i32>>());
+
+		for chunk in list.chunks_mut() {
+			for item in chunk.iter_mut() {
+				*item *= 2;
+			}
+		}
+
+		let doubled: Vec<i32> = list.as_slices().into_iter().flat_map(|s| s.iter()).map(|&n| n).collect();
+		assert!(doubled == (0i32..20i32).map(|n| n * 2).collect::<Vec<i32>>());
+	}
+
+	#[test]
+	fn smoke_sort() {
+		let mut list: Unrolled<i32> = Unrolled::new(8us);
+
+		for n in (0i32..100i32).rev() {
+			list.push(n);
+		}
+
+		list.sort_unstable();
+
+		let sorted: Vec<i32> = list.iter().map(|&n| n).collect();
+		assert!(sorted == (0i32..100i32).collect::<Vec<i32>>());
+
+		list.sort_unstable_by(|a, b| b.cmp(a));
+		let rsorted: Vec<i32> = list.iter().map(|&n| n).collect();
+		assert!(rsorted == (0i32..100i32).rev().collect::<Vec<i32>>());
+
+		list.sort_unstable_by_key(|&n| -n);
+		let by_key: Vec<i32> = list.iter().map(|&n| n).collect();
+		assert!(by_key == (0i32..100i32).rev().collect::<Vec<i32>>());
+	}
+
+	#[test]
+	fn smoke_insert() {
+		let mut list: Unrolled<i32> = Unrolled::new(8us);
+
+		for n in 0i32..20i32 {
+			list.push(n);
+		}
+
+		list.insert(0, -1);
+		list.insert(10, 100);
+		list.insert(list.len(), 999);
+
+		let expected: Vec<i32> = vec![-1i32, 0,1,2,3,4,5,6,7,8,100,9,10,11,12,13,14,15,16,17,18,19,999];
+		let actual: Vec<i32> = list.iter().map(|&n| n).collect();
+		assert!(actual == expected);
+	}
+
+	#[test]
+	fn smoke_drain() {
+		let mut list: Unrolled<i32> = Unrolled::new(8us);
+
+		for n in 0i32..20i32 {
+			list.push(n);
+		}
+
+		let drained: Vec<i32> = list.drain(5..10).collect();
+		assert!(drained == vec![5i32, 6, 7, 8, 9]);
+		assert!(list.len() == 15);
+
+		let remaining: Vec<i32> = list.iter().map(|&n| n).collect();
+		let mut expected: Vec<i32> = (0i32..5).collect();
+		expected.extend(10i32..20);
+		assert!(remaining == expected);
+	}
+
+	#[test]
+	fn smoke_index() {
+		let mut list: Unrolled<i32> = Unrolled::new(8us);
+
+		for n in 0i32..20i32 {
+			list.push(n);
+		}
+
+		assert!(list[0] == 0);
+		assert!(list[19] == 19);
+
+		list[19] = 999;
+		assert!(list[19] == 999);
+
+		let sub: Vec<i32> = list.range(6..15).map(|&n| n).collect();
+		assert!(sub == vec![6i32, 7, 8, 9, 10, 11, 12, 13, 14]);
+	}
+
 	#[test]
 	fn smoke_remove() {
-		let mut list: Unrolled<i32> = Unrolled::new(10us);
+		let mut list: Unrolled<i32> = Unrolled::new(8us);
 		list.push(1);
 		list.push(2);
 		assert!(list.remove(0) == Some(1));